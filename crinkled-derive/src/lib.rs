@@ -0,0 +1,297 @@
+//! `#[derive(Validate)]` for [`crinkled::dubious::Validate`].
+//!
+//! This crate only generates the boilerplate that walks a struct or enum
+//! field by field, delegating to each field's own [`Validate`] impl (or a
+//! `#[validate(with = ...)]`/`#[validate(skip)]` override) and rebuilding
+//! the value from the validated pieces, short-circuiting on the first
+//! error. See the `dubious::validate` module for the hand-written trait.
+//!
+//! [`Validate`]: crinkled::dubious::Validate
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Data, DeriveInput, Field, Fields, Ident};
+
+#[proc_macro_derive(Validate, attributes(validate))]
+pub fn derive_validate(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            input.generics.span(),
+            "`#[derive(Validate)]` does not support generic structs/enums yet",
+        ));
+    }
+
+    match &input.data {
+        Data::Struct(data) => expand_struct(&input.ident, &data.fields),
+        Data::Enum(data) => expand_enum(&input.ident, data),
+        Data::Union(_) => Err(syn::Error::new(
+            input.span(),
+            "`#[derive(Validate)]` does not support unions",
+        )),
+    }
+}
+
+/// How a single field should be checked, per its `#[validate(..)]` attribute.
+enum FieldStrategy {
+    /// Delegate to the field's own `Validate` impl.
+    Delegate,
+    /// `#[validate(with = path)]`: route through a free function.
+    With(syn::Path),
+    /// `#[validate(skip)]`: pass through unchecked; cannot fail.
+    Skip,
+}
+
+fn field_strategy(field: &Field) -> syn::Result<FieldStrategy> {
+    let mut strategy = FieldStrategy::Delegate;
+    for attr in &field.attrs {
+        if !attr.path().is_ident("validate") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                strategy = FieldStrategy::Skip;
+                Ok(())
+            } else if meta.path.is_ident("with") {
+                let path: syn::Path = meta.value()?.parse()?;
+                strategy = FieldStrategy::With(path);
+                Ok(())
+            } else {
+                Err(meta.error("expected `skip` or `with = path`"))
+            }
+        })?;
+    }
+    Ok(strategy)
+}
+
+/// Turns `snake_case`/a positional index into the `PascalCase` identifier
+/// used for the matching error-enum variant.
+fn error_variant_ident(field: &Field, index: usize) -> Ident {
+    match &field.ident {
+        Some(ident) => {
+            let pascal = ident
+                .to_string()
+                .split('_')
+                .filter(|segment| !segment.is_empty())
+                .map(|segment| {
+                    let mut chars = segment.chars();
+                    match chars.next() {
+                        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                        None => String::new(),
+                    }
+                })
+                .collect::<String>();
+            format_ident!("{}", pascal, span = ident.span())
+        }
+        None => format_ident!("Field{}", index),
+    }
+}
+
+/// The pieces needed to both define a `{Name}Error` enum for one set of
+/// fields (a struct, or a single enum variant) and generate the matching
+/// validation body.
+struct FieldsPlan {
+    /// `{ name, age, note }` or `(field0, field1)` or empty for a unit.
+    pattern: TokenStream2,
+    /// Rebuilds the value from the (now validated) bindings.
+    rebuild: TokenStream2,
+    /// One statement per checked field, rebinding the validated value to
+    /// its original name; `skip` fields get none and keep the binding from
+    /// `pattern`.
+    validations: Vec<TokenStream2>,
+    /// `{ Name(<Ty as Validate>::Error), Age(Box<dyn Error + Send + Sync>) }`
+    error_variants: Vec<TokenStream2>,
+}
+
+fn plan_fields(fields: &Fields, error_enum: &Ident) -> syn::Result<FieldsPlan> {
+    let mut validations = Vec::new();
+    let mut error_variants = Vec::new();
+    let mut bindings = Vec::new();
+
+    for (index, field) in fields.iter().enumerate() {
+        let strategy = field_strategy(field)?;
+        let binding = match &field.ident {
+            Some(ident) => ident.clone(),
+            None => format_ident!("field{}", index),
+        };
+        let variant = error_variant_ident(field, index);
+
+        match strategy {
+            // Already bound by the destructure pattern; nothing to check.
+            FieldStrategy::Skip => {}
+            FieldStrategy::Delegate => {
+                let ty = &field.ty;
+                error_variants.push(quote_spanned! {ty.span()=>
+                    #variant(<#ty as ::crinkled::dubious::Validate>::Error)
+                });
+                validations.push(quote! {
+                    let #binding = ::crinkled::dubious::Validate::validate(#binding)
+                        .map_err(#error_enum::#variant)?;
+                });
+            }
+            FieldStrategy::With(path) => {
+                error_variants.push(quote_spanned! {path.span()=>
+                    #variant(::std::boxed::Box<dyn ::std::error::Error + ::std::marker::Send + ::std::marker::Sync>)
+                });
+                validations.push(quote! {
+                    let #binding = (#path)(#binding)
+                        .map_err(|error| #error_enum::#variant(::std::boxed::Box::new(error)))?;
+                });
+            }
+        }
+
+        bindings.push(binding);
+    }
+
+    let (pattern, rebuild) = match fields {
+        Fields::Named(_) => (
+            quote! { { #(#bindings),* } },
+            quote! { { #(#bindings),* } },
+        ),
+        Fields::Unnamed(_) => (quote! { ( #(#bindings),* ) }, quote! { ( #(#bindings),* ) }),
+        Fields::Unit => (TokenStream2::new(), TokenStream2::new()),
+    };
+
+    Ok(FieldsPlan {
+        pattern,
+        rebuild,
+        validations,
+        error_variants,
+    })
+}
+
+fn expand_struct(ident: &Ident, fields: &Fields) -> syn::Result<TokenStream2> {
+    let error_enum = format_ident!("{}Error", ident);
+    let plan = plan_fields(fields, &error_enum)?;
+    let FieldsPlan {
+        pattern,
+        rebuild,
+        validations,
+        error_variants,
+    } = plan;
+
+    if error_variants.is_empty() {
+        return Ok(quote! {
+            impl ::crinkled::dubious::Validate for #ident {
+                type Error = ::core::convert::Infallible;
+
+                #[inline]
+                fn validate(self) -> ::std::result::Result<Self, Self::Error> {
+                    ::std::result::Result::Ok(self)
+                }
+            }
+        });
+    }
+
+    let doc = format!(
+        "Names which field of [`{ident}`] failed to validate, carrying that field's own error."
+    );
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Debug)]
+        pub enum #error_enum {
+            #(#error_variants),*
+        }
+
+        impl ::crinkled::dubious::Validate for #ident {
+            type Error = #error_enum;
+
+            fn validate(self) -> ::std::result::Result<Self, Self::Error> {
+                let #ident #pattern = self;
+                #(#validations)*
+                ::std::result::Result::Ok(#ident #rebuild)
+            }
+        }
+    })
+}
+
+fn expand_enum(ident: &Ident, data: &syn::DataEnum) -> syn::Result<TokenStream2> {
+    let error_enum = format_ident!("{}Error", ident);
+    let mut nested_defs = Vec::new();
+    let mut top_variants = Vec::new();
+    let mut arms = Vec::new();
+    let mut any_fallible = false;
+
+    for variant in &data.variants {
+        let variant_ident = &variant.ident;
+        let nested_error_enum = format_ident!("{}{}Error", ident, variant_ident);
+        let plan = plan_fields(&variant.fields, &nested_error_enum)?;
+        let FieldsPlan {
+            pattern,
+            rebuild,
+            validations,
+            error_variants,
+        } = plan;
+
+        if error_variants.is_empty() {
+            arms.push(quote! {
+                Self::#variant_ident #pattern => ::std::result::Result::Ok(Self::#variant_ident #rebuild),
+            });
+            continue;
+        }
+
+        any_fallible = true;
+        top_variants.push(quote! {
+            #variant_ident(#nested_error_enum)
+        });
+        arms.push(quote! {
+            Self::#variant_ident #pattern => (|| {
+                #(#validations)*
+                ::std::result::Result::Ok(Self::#variant_ident #rebuild)
+            })().map_err(#error_enum::#variant_ident),
+        });
+        let variant_doc = format!(
+            "Names which field of [`{ident}::{variant_ident}`] failed to validate, carrying that field's own error."
+        );
+        nested_defs.push(quote! {
+            #[doc = #variant_doc]
+            #[derive(Debug)]
+            pub enum #nested_error_enum {
+                #(#error_variants),*
+            }
+        });
+    }
+
+    if !any_fallible {
+        return Ok(quote! {
+            impl ::crinkled::dubious::Validate for #ident {
+                type Error = ::core::convert::Infallible;
+
+                #[inline]
+                fn validate(self) -> ::std::result::Result<Self, Self::Error> {
+                    ::std::result::Result::Ok(self)
+                }
+            }
+        });
+    }
+
+    let doc = format!("Names which variant/field of [`{ident}`] failed to validate.");
+    Ok(quote! {
+        #(#nested_defs)*
+
+        #[doc = #doc]
+        #[derive(Debug)]
+        pub enum #error_enum {
+            #(#top_variants),*
+        }
+
+        impl ::crinkled::dubious::Validate for #ident {
+            type Error = #error_enum;
+
+            fn validate(self) -> ::std::result::Result<Self, Self::Error> {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
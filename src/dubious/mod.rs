@@ -31,6 +31,11 @@
 //! To make the most use of the [`Dubious`] type, wrap the scrutinee in a
 //! newtype and use that as the expected type throughout your code. In our float
 //! example, we might create a `Scalar` type that wraps `f64`.
+//!
+//! Once a [`Dubious<T>`] has been validated, [`Dubious::checked`] turns it
+//! into a [`Valid<T>`]: a proof, carried in the type, that the check already
+//! happened, so a receiver that demands a `Valid<T>` never needs to
+//! re-validate.
 
 #[cfg(feature = "forward-ops")]
 pub mod ops;
@@ -38,6 +43,20 @@ pub mod validate;
 
 pub use validate::Validate;
 
+/// Marker for types that are valid outputs of a `forward-ops` arithmetic
+/// operation on [`Dubious`].
+///
+/// The `forward-ops` impls bound their `Output` by this trait, but the
+/// operators themselves return the bare `Output`, not a `Dubious`. It is
+/// blanket-implemented for every type, so the bound names the "result still
+/// needs validating" rule in the impls' `where` clauses without restricting
+/// which operators or output types are supported.
+#[cfg(feature = "forward-ops")]
+pub trait DubiousMarker {}
+
+#[cfg(feature = "forward-ops")]
+impl<T: ?Sized> DubiousMarker for T {}
+
 /// The `Dubious` type. See [the module level documentation](self) for more.
 #[derive(Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Debug, Hash)]
 pub struct Dubious<T>(T);
@@ -147,7 +166,117 @@ where
     where
         Dubious<U>: Validate<U>,
     {
-        self.ok().zip(other.ok()).map(|t| Dubious(t))
+        self.ok().zip(other.ok()).map(Dubious)
+    }
+
+    /// Validates `self`, proving the invariant once and for all.
+    ///
+    /// Unlike [`ok`](Validate::ok), the success case is not a bare `T` but a
+    /// [`Valid<T>`], so a receiver that demands `Valid<T>` gets a
+    /// compile-time guarantee that the check already happened and never
+    /// needs to re-validate.
+    #[inline]
+    pub fn checked(self) -> Result<Valid<T>, <Dubious<T> as Validate<T>>::Error> {
+        self.validate().map(Valid)
+    }
+}
+
+/// Marker for [`Validate`] impls that can never fail.
+///
+/// Blanket-implemented for every `Validate<Ok, Error = Infallible>`, this is
+/// what lets [`Dubious::trust`] exist only where validation can never fail, so
+/// the optimizer can elide the branch entirely instead of matching on an
+/// error that can't occur.
+pub trait AlwaysValid<Ok = Self>: Validate<Ok, Error = core::convert::Infallible> {}
+
+impl<T, Ok> AlwaysValid<Ok> for T where T: Validate<Ok, Error = core::convert::Infallible> {}
+
+impl<T> Dubious<T>
+where
+    Dubious<T>: AlwaysValid<T>,
+{
+    /// Proves `self` valid without a runtime check, for `Validate` impls
+    /// whose `Error` is [`Infallible`](core::convert::Infallible).
+    #[inline]
+    pub fn trust(self) -> Valid<T> {
+        match self.validate() {
+            Ok(t) => Valid(t),
+            Err(never) => match never {},
+        }
+    }
+}
+
+/// A value proven to satisfy its invariant.
+///
+/// The only way to construct a `Valid<T>` is [`Dubious::checked`] (or
+/// [`Dubious::trust`] when validation can never fail), so holding one is a
+/// compile-time guarantee the check already happened; there is nothing left
+/// to re-validate.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Debug, Hash)]
+pub struct Valid<T>(T);
+
+impl<T> Valid<T> {
+    /// Unwraps the proven value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+/// One half of a [`Dubious::validate_all`] failure on a pair: which side was
+/// invalid, carrying that side's own (possibly different) error type.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ZipError<A, B> {
+    /// The left-hand side of the pair was invalid.
+    Left(A),
+    /// The right-hand side of the pair was invalid.
+    Right(B),
+}
+
+impl<T, U> Dubious<(T, U)>
+where
+    Dubious<T>: Validate<T>,
+    Dubious<U>: Validate<U>,
+{
+    /// Validates both halves of the pair, collecting *every* error instead
+    /// of short-circuiting at the first one.
+    ///
+    /// Where [`zip_ok`](Dubious::zip_ok) discards the errors and only
+    /// returns `None`, this keeps the error of each invalid side, so a
+    /// caller can report every invalid field at once instead of only
+    /// learning that something failed. The two
+    /// sides do not need to share an error type: each collected error is
+    /// tagged with [`ZipError::Left`]/[`ZipError::Right`] to say which side
+    /// it came from.
+    #[allow(clippy::type_complexity)]
+    pub fn validate_all(
+        self,
+    ) -> Result<
+        (T, U),
+        Vec<ZipError<<Dubious<T> as Validate<T>>::Error, <Dubious<U> as Validate<U>>::Error>>,
+    > {
+        let (t, u) = self.0;
+        let mut errors = Vec::new();
+
+        let t = match Dubious(t).validate() {
+            Ok(t) => Some(t),
+            Err(e) => {
+                errors.push(ZipError::Left(e));
+                None
+            }
+        };
+        let u = match Dubious(u).validate() {
+            Ok(u) => Some(u),
+            Err(e) => {
+                errors.push(ZipError::Right(e));
+                None
+            }
+        };
+
+        match (t, u) {
+            (Some(t), Some(u)) => Ok((t, u)),
+            _ => Err(errors),
+        }
     }
 }
 
@@ -176,8 +305,72 @@ impl<T: PartialEq> PartialEq<T> for Dubious<T> {
     }
 }
 
-// /// The `Almost` type. See [the module level documentation](self) for more.
-// #[derive(Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Debug, Hash)]
-// pub struct Almost<T> {
-//     value: T,
-// }
+#[cfg(test)]
+mod tests {
+    use super::{Dubious, ZipError};
+    use crate::dubious::Validate;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Even(i32);
+
+    impl Validate for Even {
+        type Error = &'static str;
+
+        fn validate(self) -> Result<Even, Self::Error> {
+            if self.0 % 2 == 0 {
+                Ok(self)
+            } else {
+                Err("not even")
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct AlwaysOk(i32);
+
+    impl Validate for AlwaysOk {
+        type Error = std::convert::Infallible;
+
+        fn validate(self) -> Result<AlwaysOk, Self::Error> {
+            Ok(self)
+        }
+    }
+
+    #[test]
+    fn checked_proves_a_valid_value() {
+        let valid = Dubious::new(Even(2)).checked().unwrap();
+        assert_eq!(valid.into_inner(), Even(2));
+    }
+
+    #[test]
+    fn checked_reports_an_invalid_value() {
+        assert_eq!(Dubious::new(Even(3)).checked(), Err("not even"));
+    }
+
+    #[test]
+    fn trust_never_fails_for_an_infallible_validate() {
+        let valid = Dubious::new(AlwaysOk(7)).trust();
+        assert_eq!(valid.into_inner(), AlwaysOk(7));
+    }
+
+    #[test]
+    fn validate_all_collects_every_error() {
+        let pair = Dubious::new((Even(1), Even(3)));
+        assert_eq!(
+            pair.validate_all(),
+            Err(vec![ZipError::Left("not even"), ZipError::Right("not even")])
+        );
+    }
+
+    #[test]
+    fn validate_all_succeeds_when_both_sides_are_valid() {
+        let pair = Dubious::new((Even(2), Even(4)));
+        assert_eq!(pair.validate_all(), Ok((Even(2), Even(4))));
+    }
+
+    #[test]
+    fn validate_all_reports_only_the_invalid_side() {
+        let pair = Dubious::new((Even(2), Even(3)));
+        assert_eq!(pair.validate_all(), Err(vec![ZipError::Right("not even")]));
+    }
+}
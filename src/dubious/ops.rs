@@ -19,7 +19,96 @@ macro_rules! impl_bin_op {
     };
 }
 
+// Unary operators have no rhs to unwrap, just the receiver.
+macro_rules! impl_unary_op {
+    ($trait:ident, $method:ident) => {
+        impl<T> std::ops::$trait for Dubious<T>
+        where
+            T: std::ops::$trait,
+            <T as std::ops::$trait>::Output: DubiousMarker,
+        {
+            type Output = T::Output;
+
+            #[inline]
+            fn $method(self) -> Self::Output {
+                self.0.$method()
+            }
+        }
+    };
+}
+
+// Assign operators mutate `self.0` in place and return `()`, so there is no
+// `Output` of their own to bound. Instead we piggyback on the non-assign
+// sibling's `Output` to keep the "result must still be re-validated" rule
+// consistent between e.g. `a + b` and `a += b`.
+macro_rules! impl_assign_op {
+    ($trait:ident, $method:ident, $base_trait:ident) => {
+        impl<T, U> std::ops::$trait<Dubious<U>> for Dubious<T>
+        where
+            T: std::ops::$trait<U> + std::ops::$base_trait<U>,
+            <T as std::ops::$base_trait<U>>::Output: DubiousMarker,
+        {
+            #[inline]
+            fn $method(&mut self, rhs: Dubious<U>) {
+                self.0.$method(rhs.0)
+            }
+        }
+    };
+}
+
 impl_bin_op!(Add, add);
 impl_bin_op!(Sub, sub);
 impl_bin_op!(Mul, mul);
 impl_bin_op!(Div, div);
+impl_bin_op!(Rem, rem);
+impl_bin_op!(BitAnd, bitand);
+impl_bin_op!(BitOr, bitor);
+impl_bin_op!(BitXor, bitxor);
+impl_bin_op!(Shl, shl);
+impl_bin_op!(Shr, shr);
+
+impl_unary_op!(Neg, neg);
+impl_unary_op!(Not, not);
+
+impl_assign_op!(AddAssign, add_assign, Add);
+impl_assign_op!(SubAssign, sub_assign, Sub);
+impl_assign_op!(MulAssign, mul_assign, Mul);
+impl_assign_op!(DivAssign, div_assign, Div);
+impl_assign_op!(RemAssign, rem_assign, Rem);
+impl_assign_op!(BitAndAssign, bitand_assign, BitAnd);
+impl_assign_op!(BitOrAssign, bitor_assign, BitOr);
+impl_assign_op!(BitXorAssign, bitxor_assign, BitXor);
+impl_assign_op!(ShlAssign, shl_assign, Shl);
+impl_assign_op!(ShrAssign, shr_assign, Shr);
+
+#[cfg(test)]
+mod tests {
+    use super::Dubious;
+
+    #[test]
+    fn binary_ops_forward_and_unwrap() {
+        assert_eq!(Dubious::new(2) + Dubious::new(3), 5);
+        assert_eq!(Dubious::new(7) % Dubious::new(2), 1);
+        assert_eq!(Dubious::new(0b1010) & Dubious::new(0b1100), 0b1000);
+        assert_eq!(Dubious::new(1) << Dubious::new(3u32), 8);
+    }
+
+    #[test]
+    fn unary_ops_forward_and_unwrap() {
+        assert_eq!(-Dubious::new(5), -5);
+        assert_eq!(!Dubious::new(0u8), 0xffu8);
+    }
+
+    #[test]
+    fn assign_ops_mutate_in_place() {
+        let mut x = Dubious::new(10);
+        x += Dubious::new(5);
+        assert_eq!(x, 15);
+
+        x %= Dubious::new(4);
+        assert_eq!(x, 3);
+
+        x <<= Dubious::new(2u32);
+        assert_eq!(x, 12);
+    }
+}
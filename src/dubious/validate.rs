@@ -32,4 +32,24 @@ pub trait Validate<Ok = Self> {
     {
         self.validate().err()
     }
+
+    /// Like [`validate`](Validate::validate), but for a single value there
+    /// is only ever at most one error to report, so this default just
+    /// wraps it in a one-element [`Vec`].
+    ///
+    /// The interesting case is composite values with more than one
+    /// component to check, e.g.
+    /// [`Dubious::<(T, U)>::validate_all`](crate::dubious::Dubious::validate_all),
+    /// which keeps validating every component instead of stopping at the
+    /// first failure, collecting *all* of the errors rather than only the
+    /// first one. That method is a separate inherent method rather than an
+    /// override of this default, since the two halves of a pair may have
+    /// different `Error` types and this trait only has room for one.
+    #[inline]
+    fn validate_all(self) -> Result<Ok, Vec<Self::Error>>
+    where
+        Self: Sized,
+    {
+        self.validate().map_err(|error| vec![error])
+    }
 }
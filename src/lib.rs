@@ -0,0 +1,14 @@
+//! `crinkled` — wrappers for values that might not be valid yet.
+//!
+//! See [`dubious`] for the core [`Dubious`](dubious::Dubious) type.
+//!
+//! With the `derive` feature enabled, `#[derive(Validate)]` generates a
+//! [`dubious::Validate`] impl for a struct or enum from its fields' own
+//! impls, so a large aggregate can be wrapped in [`Dubious`](dubious::Dubious)
+//! and validated at the boundary without hand-written plumbing.
+
+pub mod dubious;
+pub mod refined;
+
+#[cfg(feature = "derive")]
+pub use crinkled_derive::Validate;
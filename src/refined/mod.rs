@@ -0,0 +1,153 @@
+//! Long-lived validated state.
+//!
+//! [`Dubious`](crate::dubious::Dubious) is for the one-shot case: consume a
+//! value, validate it once, and hand the proven result onward. It has
+//! nothing to say about a value that is mutated repeatedly and must stay
+//! inside some invariant *between* edits, rather than only at the end.
+//!
+//! [`Refined<T, C>`] fills that gap: a cell that can only ever be observed
+//! holding a `T` that satisfies the predicate `C`, re-checking cheaply after
+//! every edit and dropping to empty the moment the invariant breaks.
+
+use std::marker::PhantomData;
+
+/// A predicate over `T`, used as the zero-sized invariant parameter of
+/// [`Refined<T, C>`].
+///
+/// `C` carries no state of its own; it exists purely to name a check at the
+/// type level, so two `Refined<T, C>`s with different `C`s are different
+/// types even though they both hold a `T`.
+pub trait Check<T> {
+    /// Returns whether `t` satisfies this invariant.
+    fn check(t: &T) -> bool;
+}
+
+/// A cell holding a `T` that satisfies `C`, or nothing.
+///
+/// Unlike [`Dubious`](crate::dubious::Dubious), which is consumed once it is
+/// validated, `Refined` is meant to live for a while and be mutated in
+/// place: every write re-checks the invariant, so the value is either
+/// `Some` and valid, or `None`.
+pub struct Refined<T, C> {
+    value: Option<T>,
+    _check: PhantomData<fn() -> C>,
+}
+
+impl<T, C> Refined<T, C>
+where
+    C: Check<T>,
+{
+    /// Creates an empty cell.
+    #[inline]
+    pub const fn empty() -> Self {
+        Refined {
+            value: None,
+            _check: PhantomData,
+        }
+    }
+
+    /// Creates a cell from `t`, checking it up front.
+    #[inline]
+    pub fn new(t: T) -> Self {
+        let mut refined = Self::empty();
+        refined.set_valid(t);
+        refined
+    }
+
+    /// Returns the held value, if any.
+    #[inline]
+    pub fn get(&self) -> Option<&T> {
+        self.value.as_ref()
+    }
+
+    /// Stores `t` iff it satisfies `C`, otherwise empties the cell.
+    #[inline]
+    pub fn set_valid(&mut self, t: T) {
+        self.value = C::check(&t).then_some(t);
+    }
+
+    /// Overwrites the held value with `t` only if `t` satisfies `C`, leaving
+    /// the existing value (if any) untouched otherwise.
+    ///
+    /// Returns whether `t` was accepted.
+    #[inline]
+    pub fn set_if_valid(&mut self, t: T) -> bool {
+        if C::check(&t) {
+            self.value = Some(t);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Applies `f` to the held value and re-checks the result, dropping to
+    /// empty if the invariant no longer holds.
+    ///
+    /// Does nothing if the cell is currently empty.
+    #[inline]
+    pub fn update(&mut self, f: impl FnOnce(T) -> T) {
+        if let Some(t) = self.value.take() {
+            self.set_valid(f(t));
+        }
+    }
+}
+
+impl<T, C> Default for Refined<T, C>
+where
+    C: Check<T>,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Check, Refined};
+
+    struct Positive;
+
+    impl Check<i32> for Positive {
+        fn check(t: &i32) -> bool {
+            *t > 0
+        }
+    }
+
+    #[test]
+    fn new_accepts_a_valid_value() {
+        let cell: Refined<i32, Positive> = Refined::new(5);
+        assert_eq!(cell.get(), Some(&5));
+    }
+
+    #[test]
+    fn new_rejects_an_invalid_value() {
+        let cell: Refined<i32, Positive> = Refined::new(-5);
+        assert_eq!(cell.get(), None);
+    }
+
+    #[test]
+    fn set_if_valid_leaves_the_existing_value_on_rejection() {
+        let mut cell: Refined<i32, Positive> = Refined::new(5);
+        assert!(!cell.set_if_valid(-1));
+        assert_eq!(cell.get(), Some(&5));
+
+        assert!(cell.set_if_valid(10));
+        assert_eq!(cell.get(), Some(&10));
+    }
+
+    #[test]
+    fn update_re_checks_after_applying_f() {
+        let mut cell: Refined<i32, Positive> = Refined::new(5);
+
+        cell.update(|t| t - 3);
+        assert_eq!(cell.get(), Some(&2));
+
+        cell.update(|t| t - 100);
+        assert_eq!(cell.get(), None);
+
+        // Once empty, `update` has nothing to apply `f` to.
+        cell.update(|t| t + 1000);
+        assert_eq!(cell.get(), None);
+    }
+}
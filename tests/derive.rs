@@ -0,0 +1,106 @@
+//! Integration tests for `#[derive(Validate)]` (behind the `derive` feature).
+
+use crinkled::dubious::Validate;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Even(i32);
+
+impl Validate for Even {
+    type Error = &'static str;
+
+    fn validate(self) -> Result<Even, Self::Error> {
+        if self.0 % 2 == 0 {
+            Ok(self)
+        } else {
+            Err("not even")
+        }
+    }
+}
+
+#[derive(Debug, crinkled::Validate)]
+struct Pair {
+    a: Even,
+    b: Even,
+}
+
+#[test]
+fn struct_validates_every_field_in_order() {
+    assert!(matches!(
+        (Pair { a: Even(2), b: Even(4) }).validate(),
+        Ok(Pair { a: Even(2), b: Even(4) })
+    ));
+
+    match (Pair { a: Even(1), b: Even(3) }).validate() {
+        Err(PairError::A("not even")) => {}
+        other => panic!("expected PairError::A, got {other:?}"),
+    }
+}
+
+#[derive(Debug, crinkled::Validate)]
+struct WithSkip {
+    checked: Even,
+    #[validate(skip)]
+    unchecked: i32,
+}
+
+#[test]
+fn skip_field_passes_through_unchecked() {
+    let value = WithSkip {
+        checked: Even(2),
+        unchecked: 13,
+    };
+    let validated = value
+        .validate()
+        .expect("unchecked field must not block validation");
+    assert_eq!(validated.unchecked, 13);
+}
+
+#[derive(Debug)]
+struct NotPositive;
+
+impl std::fmt::Display for NotPositive {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "must be positive")
+    }
+}
+
+impl std::error::Error for NotPositive {}
+
+fn validate_positive(x: i32) -> Result<i32, NotPositive> {
+    if x > 0 {
+        Ok(x)
+    } else {
+        Err(NotPositive)
+    }
+}
+
+#[derive(Debug, crinkled::Validate)]
+struct WithFn {
+    #[validate(with = validate_positive)]
+    value: i32,
+}
+
+#[test]
+fn with_attribute_routes_through_free_function() {
+    assert!(WithFn { value: 5 }.validate().is_ok());
+    assert!(WithFn { value: -5 }.validate().is_err());
+}
+
+#[derive(Debug, crinkled::Validate)]
+enum Shape {
+    Circle { radius: Even },
+    Point,
+}
+
+#[test]
+fn enum_validates_the_active_variants_fields() {
+    assert!(matches!(
+        Shape::Circle { radius: Even(4) }.validate(),
+        Ok(Shape::Circle { radius: Even(4) })
+    ));
+    assert!(matches!(Shape::Point.validate(), Ok(Shape::Point)));
+    assert!(matches!(
+        Shape::Circle { radius: Even(3) }.validate(),
+        Err(ShapeError::Circle(ShapeCircleError::Radius("not even")))
+    ));
+}